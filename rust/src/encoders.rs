@@ -0,0 +1,300 @@
+//! Batch sequence encoders exposed to Python as `resp_toolkit_rust_ext.encoders`.
+
+use std::fmt;
+
+use numpy::{PyArray1, PyArray2, PyArray3};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use pyo3::wrap_pyfunction;
+use rayon::prelude::*;
+
+/// Canonical amino acid alphabet used when the caller does not supply one, in
+/// the standard ordering plus a trailing gap symbol.
+const DEFAULT_ALPHABET: [char; 21] = [
+    'A', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'K', 'L', 'M', 'N', 'P', 'Q', 'R', 'S', 'T', 'V', 'W', 'Y', '-',
+];
+
+/// How to reconcile a sequence's length against `max_length`.
+#[derive(Clone, Copy)]
+enum PadMode {
+    Pre,
+    Post,
+    Error,
+}
+
+impl PadMode {
+    fn parse(mode: &str) -> PyResult<Self> {
+        match mode {
+            "post" => Ok(PadMode::Post),
+            "pre" => Ok(PadMode::Pre),
+            "error" => Ok(PadMode::Error),
+            other => Err(PyValueError::new_err(format!(
+                "pad must be one of 'post', 'pre', 'error', got '{other}'"
+            ))),
+        }
+    }
+}
+
+/// Error raised while encoding a single sequence, tagged with its position in
+/// the batch so callers can find the offender without re-scanning the input.
+#[derive(Debug)]
+enum EncodeError {
+    Alphabet { sequence_index: usize, character: char },
+    TooLong { sequence_index: usize, length: usize, max_length: usize },
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodeError::Alphabet { sequence_index, character } => write!(
+                f,
+                "sequence {sequence_index}: character '{character}' is not in the alphabet"
+            ),
+            EncodeError::TooLong { sequence_index, length, max_length } => write!(
+                f,
+                "sequence {sequence_index}: length {length} exceeds max_length {max_length}"
+            ),
+        }
+    }
+}
+
+/// A 256-entry lookup from ASCII byte to alphabet index, built once per call
+/// so per-residue mapping in the hot loop is an array index rather than a
+/// hash lookup.
+struct Lookup {
+    table: [i32; 256],
+    size: usize,
+}
+
+impl Lookup {
+    fn default_aa() -> Self {
+        let mut table = [-1i32; 256];
+        for (i, c) in DEFAULT_ALPHABET.iter().enumerate() {
+            table[*c as usize] = i as i32;
+        }
+        Lookup { table, size: DEFAULT_ALPHABET.len() }
+    }
+
+    fn from_dict(dict: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let mut table = [-1i32; 256];
+        let mut size = 0usize;
+        for (key, value) in dict.iter() {
+            let residue: String = key.extract()?;
+            let index: usize = value.extract()?;
+            if residue.len() != 1 || !residue.is_ascii() {
+                return Err(PyValueError::new_err(
+                    "alphabet keys must be single ASCII characters",
+                ));
+            }
+            table[residue.as_bytes()[0] as usize] = index as i32;
+            size = size.max(index + 1);
+        }
+        Ok(Lookup { table, size })
+    }
+
+    fn get(&self, c: char, seq_idx: usize) -> Result<usize, EncodeError> {
+        if !c.is_ascii() {
+            return Err(EncodeError::Alphabet { sequence_index: seq_idx, character: c });
+        }
+        let idx = self.table[c as usize];
+        if idx < 0 {
+            return Err(EncodeError::Alphabet { sequence_index: seq_idx, character: c });
+        }
+        Ok(idx as usize)
+    }
+}
+
+fn build_lookup(alphabet: Option<&Bound<'_, PyDict>>) -> PyResult<Lookup> {
+    match alphabet {
+        Some(dict) => Lookup::from_dict(dict),
+        None => Ok(Lookup::default_aa()),
+    }
+}
+
+/// Resolve how a sequence's characters map onto the `max_len` output window:
+/// which slice of the (possibly over-length) sequence to keep, and where in
+/// the output that slice starts. For `pad="pre"`, truncation keeps the tail
+/// of the sequence so over-length and padded-short sequences both end up
+/// right-aligned within the window.
+fn resolve_window(
+    len: usize,
+    max_len: usize,
+    seq_idx: usize,
+    pad_mode: PadMode,
+) -> Result<(usize, usize, usize), EncodeError> {
+    if len > max_len {
+        if let PadMode::Error = pad_mode {
+            return Err(EncodeError::TooLong { sequence_index: seq_idx, length: len, max_length: max_len });
+        }
+    }
+    let effective_len = len.min(max_len);
+    let (chars_start, dest_start) = match pad_mode {
+        PadMode::Pre if len > max_len => (len - max_len, 0),
+        PadMode::Pre => (0, max_len - effective_len),
+        PadMode::Post | PadMode::Error => (0, 0),
+    };
+    Ok((chars_start, dest_start, effective_len))
+}
+
+fn encode_flat_one(
+    seq: &str,
+    seq_idx: usize,
+    max_len: usize,
+    lookup: &Lookup,
+    pad_mode: PadMode,
+) -> Result<Vec<f32>, EncodeError> {
+    let chars: Vec<char> = seq.chars().collect();
+    let (chars_start, dest_start, effective_len) = resolve_window(chars.len(), max_len, seq_idx, pad_mode)?;
+    let mut out = vec![0.0f32; max_len * lookup.size];
+    for (pos, &c) in chars[chars_start..chars_start + effective_len].iter().enumerate() {
+        let idx = lookup.get(c, seq_idx)?;
+        out[(dest_start + pos) * lookup.size + idx] = 1.0;
+    }
+    Ok(out)
+}
+
+fn encode_3d_one(
+    seq: &str,
+    seq_idx: usize,
+    max_len: usize,
+    lookup: &Lookup,
+    pad_mode: PadMode,
+) -> Result<Vec<Vec<f32>>, EncodeError> {
+    let chars: Vec<char> = seq.chars().collect();
+    let (chars_start, dest_start, effective_len) = resolve_window(chars.len(), max_len, seq_idx, pad_mode)?;
+    let mut out = vec![vec![0.0f32; lookup.size]; max_len];
+    for (pos, &c) in chars[chars_start..chars_start + effective_len].iter().enumerate() {
+        let idx = lookup.get(c, seq_idx)?;
+        out[dest_start + pos][idx] = 1.0;
+    }
+    Ok(out)
+}
+
+fn encode_integer_one(
+    seq: &str,
+    seq_idx: usize,
+    max_len: usize,
+    lookup: &Lookup,
+    pad_mode: PadMode,
+) -> Result<Vec<i64>, EncodeError> {
+    let chars: Vec<char> = seq.chars().collect();
+    let (chars_start, dest_start, effective_len) = resolve_window(chars.len(), max_len, seq_idx, pad_mode)?;
+    let mut out = vec![-1i64; max_len];
+    for (pos, &c) in chars[chars_start..chars_start + effective_len].iter().enumerate() {
+        let idx = lookup.get(c, seq_idx)?;
+        out[dest_start + pos] = idx as i64;
+    }
+    Ok(out)
+}
+
+fn extract_sequences(sequences: &Bound<'_, PyList>) -> PyResult<Vec<String>> {
+    sequences.iter().map(|item| item.extract::<String>()).collect()
+}
+
+fn resolve_max_len(seqs: &[String], max_length: Option<usize>) -> usize {
+    max_length.unwrap_or_else(|| seqs.iter().map(|s| s.chars().count()).max().unwrap_or(0))
+}
+
+/// How many sequences to encode between `Python::check_signals` checkpoints,
+/// so a pending `KeyboardInterrupt` aborts a long batch promptly instead of
+/// only once the whole GIL-released run finishes.
+const CANCEL_CHECK_INTERVAL: usize = 4096;
+
+/// Encode `seqs` in chunks, releasing the GIL to encode each chunk in
+/// parallel and re-acquiring it between chunks to check for a pending
+/// interrupt. Bailing out early drops the partially-built `out`.
+fn encode_in_chunks<T, F>(py: Python<'_>, seqs: &[String], encode_one: F) -> PyResult<Vec<T>>
+where
+    T: Send,
+    F: Fn(&str, usize) -> Result<T, EncodeError> + Sync,
+{
+    let mut out = Vec::with_capacity(seqs.len());
+    for (chunk_idx, chunk) in seqs.chunks(CANCEL_CHECK_INTERVAL).enumerate() {
+        let offset = chunk_idx * CANCEL_CHECK_INTERVAL;
+        let encoded_chunk = py
+            .allow_threads(|| {
+                chunk
+                    .par_iter()
+                    .enumerate()
+                    .map(|(i, s)| encode_one(s, offset + i))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        out.extend(encoded_chunk);
+        py.check_signals()?;
+    }
+    Ok(out)
+}
+
+/// One-hot encode a batch of sequences into a flat `(n_sequences, max_len * alphabet_size)` layout.
+#[pyfunction]
+#[pyo3(signature = (sequences, alphabet=None, max_length=None, pad="post"))]
+fn onehot_flat_encode_list<'py>(
+    py: Python<'py>,
+    sequences: &Bound<'py, PyList>,
+    alphabet: Option<&Bound<'py, PyDict>>,
+    max_length: Option<usize>,
+    pad: &str,
+) -> PyResult<Bound<'py, PyArray2<f32>>> {
+    let seqs = extract_sequences(sequences)?;
+    let n = seqs.len();
+    let lookup = build_lookup(alphabet)?;
+    let max_len = resolve_max_len(&seqs, max_length);
+    let pad_mode = PadMode::parse(pad)?;
+    let width = max_len * lookup.size;
+    let encoded = encode_in_chunks(py, &seqs, |s, i| encode_flat_one(s, i, max_len, &lookup, pad_mode))?;
+    let flat: Vec<f32> = encoded.into_iter().flatten().collect();
+    PyArray1::from_vec_bound(py, flat).reshape([n, width])
+}
+
+/// One-hot encode a batch of sequences into a `(n_sequences, max_len, alphabet_size)` layout.
+#[pyfunction]
+#[pyo3(signature = (sequences, alphabet=None, max_length=None, pad="post"))]
+fn onehot_3d_encode_list<'py>(
+    py: Python<'py>,
+    sequences: &Bound<'py, PyList>,
+    alphabet: Option<&Bound<'py, PyDict>>,
+    max_length: Option<usize>,
+    pad: &str,
+) -> PyResult<Bound<'py, PyArray3<f32>>> {
+    let seqs = extract_sequences(sequences)?;
+    let n = seqs.len();
+    let lookup = build_lookup(alphabet)?;
+    let max_len = resolve_max_len(&seqs, max_length);
+    let pad_mode = PadMode::parse(pad)?;
+    let alphabet_size = lookup.size;
+    let encoded = encode_in_chunks(py, &seqs, |s, i| encode_3d_one(s, i, max_len, &lookup, pad_mode))?;
+    let flat: Vec<f32> = encoded.into_iter().flatten().flatten().collect();
+    PyArray1::from_vec_bound(py, flat).reshape([n, max_len, alphabet_size])
+}
+
+/// Integer-encode a batch of sequences into a `(n_sequences, max_len)` layout.
+#[pyfunction]
+#[pyo3(signature = (sequences, alphabet=None, max_length=None, pad="post"))]
+fn integer_encode_list<'py>(
+    py: Python<'py>,
+    sequences: &Bound<'py, PyList>,
+    alphabet: Option<&Bound<'py, PyDict>>,
+    max_length: Option<usize>,
+    pad: &str,
+) -> PyResult<Bound<'py, PyArray2<i64>>> {
+    let seqs = extract_sequences(sequences)?;
+    let n = seqs.len();
+    let lookup = build_lookup(alphabet)?;
+    let max_len = resolve_max_len(&seqs, max_length);
+    let pad_mode = PadMode::parse(pad)?;
+    let encoded = encode_in_chunks(py, &seqs, |s, i| encode_integer_one(s, i, max_len, &lookup, pad_mode))?;
+    let flat: Vec<i64> = encoded.into_iter().flatten().collect();
+    PyArray1::from_vec_bound(py, flat).reshape([n, max_len])
+}
+
+/// Register this module's functions on an already-created `encoders` submodule.
+/// Initialization is manual (no `#[pymodule]` here) so only the crate root
+/// exports a `PyInit_*` symbol, keeping the wheel's extension-symbol audit clean.
+pub(crate) fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(onehot_flat_encode_list, m)?)?;
+    m.add_function(wrap_pyfunction!(onehot_3d_encode_list, m)?)?;
+    m.add_function(wrap_pyfunction!(integer_encode_list, m)?)?;
+    Ok(())
+}
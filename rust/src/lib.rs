@@ -1,22 +1,19 @@
-use pyo3::{
-    pymodule,
-    types::{PyDict, PyModule, PyList},
-    Bound, FromPyObject, PyObject, PyResult, Python,
-}
+mod encoders;
 
-#[pyfunction]
-fn onehot_flat_encode_list() -> PyResult<String> {
-}
+use pyo3::prelude::*;
+use pyo3::types::PyModule;
 
-#[pyfunction]
-fn onehot_3d_encode_list() -> PyResult<String> {
-}
+#[pymodule]
+fn resp_toolkit_rust_ext(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let encoders_module = PyModule::new_bound(py, "encoders")?;
+    encoders::register(&encoders_module)?;
+    m.add_submodule(&encoders_module)?;
 
-#[pyfunction]
-fn integer_encode_list() -> PyResult<String> {
-}
+    // Register under the dotted path so `from resp_toolkit_rust_ext.encoders
+    // import ...` works the same as for a regular Python package.
+    py.import_bound("sys")?
+        .getattr("modules")?
+        .set_item("resp_toolkit_rust_ext.encoders", &encoders_module)?;
 
-#[pymodule]
-fn resp_toolkit_rust_ext(m: &Bound<'_, PyModule>) -> PyResult<()> {
-    m.add_function(wrap_pyfunction!(onehot_flat_encode_list, m)?)?;
-}
\ No newline at end of file
+    Ok(())
+}